@@ -78,19 +78,170 @@ The expansion of the above includes the following:
 
   - An explicit `cause` which returns the boxed error *itself* as the cause.  This is distinct from the behaviour of an *automatic* `cause`.
 
-## FAQ
+## Unitary variants
 
-* *Can I use unitary variants; ones without a payload?*
+A variant may also be declared with no payload at all, in which case it is written without the trailing `(Ty)`:
 
-  No, not as yet.  Maybe if there's demand.
+```rust
+#[macro_use] extern crate error_type;
 
-* *Can I use tuple variants with more than one element?*
+error_type! {
+    #[derive(Debug)]
+    pub enum LibError {
+        NotFound {
+            disp (_e, fmt) write!(fmt, "not found");
+            desc (_e) "not found";
+        }
+    }
+}
+# fn main() {}
+```
 
-  No.  This would likely be rather inconvenient to implement, due to the way the various parts of the implementation are constructed.  Not impossible, though.
+Since a unitary variant has no payload to forward to, there is no automatic `disp`/`desc` behaviour for it: both clauses must be given explicitly.
+
+## Struct variants
+
+A variant may carry several named fields, in which case `disp`/`desc`/`cause` clauses can refer to the fields directly by name:
+
+```rust
+#[macro_use] extern crate error_type;
+
+error_type! {
+    #[derive(Debug)]
+    pub enum LibError {
+        Parse { line: usize, msg: String } {
+            disp (fmt) write!(fmt, "{} at line {}", msg, line);
+            desc (_e) "parse error";
+        }
+    }
+}
+# fn main() {}
+```
+
+As with unitary variants, there is no payload to forward `disp`/`desc` to automatically, so both must be given explicitly.  There is also no implicit `From` conversion for a struct variant, since there's no single payload type to convert from.
+
+## Capturing a backtrace
+
+With the `backtrace` cargo feature enabled, the [`Backtraced`](struct.Backtraced.html) wrapper can be used to capture a stack backtrace at the point an error is first constructed.  Use `Backtraced<AppError>` in place of `AppError` as your `Result`'s error type; no changes to the `error_type!` definition itself are required.  `Backtraced<K>` only implements `From<K>`, not `From` of whatever `K` itself converts from, so convert the source error into `AppError` first (e.g. `AppError::from(err)`, or `err.into()` where the target is already known to be `AppError`) and wrap the result in `Backtraced::from`/`.into()`. With the feature disabled (the default), `Backtraced` doesn't exist and there is no change in behaviour or dependencies.
+
+## Chaining context onto an error
+
+A tuple variant can be marked with a `context;` clause to make it the target of a `ResultExt::chain_err` method.  `error_type!` generates a fresh `ResultExt` trait alongside the error type itself (rather than sharing one from this crate, which would run afoul of the orphan rule), so it's already in scope wherever the error type is defined; no `use` is needed:
+
+```rust
+#[macro_use] extern crate error_type;
+
+use std::borrow::Cow;
+use std::error::Error;
+
+error_type! {
+    #[derive(Debug)]
+    pub enum AppError {
+        Message((Cow<'static, str>, Box<Error>)) {
+            context;
+            disp (e, fmt) write!(fmt, "{}", e.0);
+            desc (e) &*e.0;
+            cause (e) Some(&*e.1);
+        },
+        Io(std::io::Error) { cause; },
+    }
+}
+
+fn load() -> Result<(), AppError> {
+    std::fs::File::open("config.toml")
+        .chain_err(|| "while loading config")?;
+    Ok(())
+}
+# fn main() {}
+```
+
+`chain_err` converts any `std::error::Error` into the designated variant, storing the supplied message alongside the original error, moved into a `Box`, as its cause.
+
+## Format-string sugar
+
+Writing `disp (arg, fmt) write!(fmt, "...")` is rather verbose for the common case of just formatting a message, so `disp` also accepts a bare format string (plus arguments), much like `write!` itself:
+
+```rust
+#[macro_use] extern crate error_type;
+
+error_type! {
+    #[derive(Debug)]
+    pub enum LibError {
+        Parse { line: usize, msg: String } {
+            disp "{} at line {}", msg, line;
+            desc "parse error";
+        }
+    }
+}
+# fn main() {}
+```
+
+`disp "...", args...;` expands to a normal `disp` clause that calls `write!` with the given format string and arguments; `desc "literal";` is shorthand for `desc (_e) "literal";`.  This works the same way for tuple, unitary and struct variants.
 
-* *Can I use struct variants?*
+For a tuple variant, the bare sugar doesn't bind a name for the payload, so if an argument needs to reach it, use the `disp (arg) "...", args...;` form instead, which names the payload binding just like the non-sugar `disp (arg, fmt) ...;` form does — e.g. `disp (v) "bad value: {}", v;` for `BadValue(i32)`.
 
-  No, for much the same reason as tuple variants.
+## `Error::source`
+
+Alongside the deprecated `cause`, `error_type!` also implements `Error::source`, which requires the returned error to be bounded by `'static`.  An explicit `cause (arg) expr;` clause drives both, provided `expr` meets that bound (true of the common case of a boxed owned error, since `Box<Error>` defaults to `Box<Error + 'static>`):
+
+```rust
+#[macro_use] extern crate error_type;
+
+use std::error::Error;
+
+error_type! {
+    #[derive(Debug)]
+    pub enum LibError {
+        Wrapped(Box<Error>) {
+            desc (e) e.description();
+            cause (e) Some(&**e);
+        },
+    }
+}
+
+fn print_source(e: &LibError) {
+    if let Some(source) = e.source() {
+        println!("caused by: {}", source);
+    }
+}
+# fn main() {}
+```
+
+The automatic `cause;` shorthand is different: it forwards to the payload's own `cause()`, which returns `Option<&Error>` with no `'static` bound, so it can't be reused for `source` -- `source` instead forwards to the payload's own `source()` for variants using bare `cause;`.  `cause ()` (no cause at all) likewise gives `None` for both.
+
+## Flattening a sub-error into a super-error
+
+A tuple variant whose payload is itself an `error_type!`-generated enum can be marked with `flatten (SubVariant, ..);`, listing that enum's variant names.  Instead of wrapping the whole sub-error in one variant, the generated `From` impl matches each listed sub-variant and forwards its payload straight into whichever of this enum's own variants already accepts it, so errors compose without an extra level of nesting:
+
+```rust
+#[macro_use] extern crate error_type;
+
+mod other {
+    error_type! {
+        #[derive(Debug)]
+        pub enum SubError {
+            Io(std::io::Error) { cause; },
+        }
+    }
+}
+
+error_type! {
+    #[derive(Debug)]
+    pub enum AppError {
+        Io(std::io::Error) { cause; },
+        Sub(other::SubError) { flatten (Io); },
+    }
+}
+# fn main() {}
+```
+
+Here, converting an `other::SubError::Io(e)` into an `AppError` produces `AppError::Io(e)` directly, rather than `AppError::Sub(other::SubError::Io(e))`.  Each listed sub-variant must be a tuple variant, and its payload type must already have a matching `From` impl on the super-enum.
+
+## FAQ
+
+* *Can I use tuple variants with more than one element?*
+
+  No.  This would likely be rather inconvenient to implement, due to the way the various parts of the implementation are constructed.  Not impossible, though.
 
 * *Can I have fields common to all variants; i.e. have the enum wrapped in a struct?*
 
@@ -98,6 +249,72 @@ The expansion of the above includes the following:
 
 */
 
+#[cfg(feature = "backtrace")]
+extern crate backtrace;
+
+/**
+Wraps an `error_type!`-generated error kind `K`, capturing a
+[`backtrace::Backtrace`](https://docs.rs/backtrace) the moment it is
+constructed via `From`.
+
+This is only available with the `backtrace` cargo feature enabled; with it
+disabled (the default), this type isn't compiled in, so there's no cost to
+crates that don't want it.  See the crate documentation for usage.
+*/
+#[cfg(feature = "backtrace")]
+pub struct Backtraced<K> {
+    kind: K,
+    backtrace: ::backtrace::Backtrace,
+}
+
+#[cfg(feature = "backtrace")]
+impl<K> Backtraced<K> {
+    /// The backtrace captured when this error was constructed.
+    pub fn backtrace(&self) -> &::backtrace::Backtrace {
+        &self.backtrace
+    }
+
+    /// The underlying error kind.
+    pub fn kind(&self) -> &K {
+        &self.kind
+    }
+}
+
+#[cfg(feature = "backtrace")]
+impl<K: ::std::fmt::Debug> ::std::fmt::Debug for Backtraced<K> {
+    fn fmt(&self, fmt: &mut ::std::fmt::Formatter) -> ::std::result::Result<(), ::std::fmt::Error> {
+        ::std::fmt::Debug::fmt(&self.kind, fmt)
+    }
+}
+
+#[cfg(feature = "backtrace")]
+impl<K: ::std::fmt::Display> ::std::fmt::Display for Backtraced<K> {
+    fn fmt(&self, fmt: &mut ::std::fmt::Formatter) -> ::std::result::Result<(), ::std::fmt::Error> {
+        ::std::fmt::Display::fmt(&self.kind, fmt)
+    }
+}
+
+#[cfg(feature = "backtrace")]
+impl<K: ::std::error::Error> ::std::error::Error for Backtraced<K> {
+    fn description(&self) -> &str {
+        self.kind.description()
+    }
+
+    fn cause(&self) -> ::std::option::Option<&::std::error::Error> {
+        self.kind.cause()
+    }
+}
+
+#[cfg(feature = "backtrace")]
+impl<K> ::std::convert::From<K> for Backtraced<K> {
+    fn from(kind: K) -> Backtraced<K> {
+        Backtraced {
+            kind,
+            backtrace: ::backtrace::Backtrace::new(),
+        }
+    }
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! error_type_as_item {
@@ -111,16 +328,71 @@ macro_rules! error_type_var_body_emit {
     Nothing left.
     */
     (
-        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident
+        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident
     ) => {
         // Done.
     };
 
+    /*
+    context () clause: no context carrier.
+    */
+    (
+        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
+        context ()
+        $($tail:tt)*
+    ) => {
+        error_type_var_body_emit! {
+            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr, $es_tr $($tail)*
+        }
+    };
+
+    /*
+    context (yes) clause: this variant is the context carrier for `chain_err`.
+    */
+    (
+        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
+        context (yes)
+        $($tail:tt)*
+    ) => {
+        // Defined locally (rather than in the `error_type` crate) so this
+        // impl doesn't fall foul of the orphan rule: `Result<T, __ChainErr>`
+        // is a foreign type, so the trait it's implemented against must be
+        // local to this crate.
+        pub trait ResultExt<T> {
+            /// Converts `self` into a `Result<T, _>`, attaching the message
+            /// produced by `f` as context and recording the original error
+            /// as the cause of the new one.
+            fn chain_err<F, D>(self, f: F) -> ::std::result::Result<T, $err_name>
+            where
+                F: FnOnce() -> D,
+                D: ::std::convert::Into<::std::borrow::Cow<'static, str>>;
+        }
+
+        impl<T, __ChainErr> ResultExt<T> for ::std::result::Result<T, __ChainErr>
+        where
+            __ChainErr: ::std::error::Error + 'static,
+        {
+            fn chain_err<F, D>(self, f: F) -> ::std::result::Result<T, $err_name>
+            where
+                F: FnOnce() -> D,
+                D: ::std::convert::Into<::std::borrow::Cow<'static, str>>,
+            {
+                self.map_err(|e| {
+                    $err_name::$var_name((f().into(), ::std::boxed::Box::new(e)))
+                })
+            }
+        }
+
+        error_type_var_body_emit! {
+            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr, $es_tr $($tail)*
+        }
+    };
+
     /*
     disp () clause.
     */
     (
-        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident,
+        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
         disp ()
         $($tail:tt)*
     ) => {
@@ -131,7 +403,7 @@ macro_rules! error_type_var_body_emit {
         }
 
         error_type_var_body_emit! {
-            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr $($tail)*
+            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr, $es_tr $($tail)*
         }
     };
 
@@ -139,7 +411,7 @@ macro_rules! error_type_var_body_emit {
     disp ((arg, fmt) expr) clause.
     */
     (
-        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident,
+        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
         disp (($disp_arg:ident, $disp_fmt:ident) $disp_expr:expr)
         $($tail:tt)*
     ) => {
@@ -152,7 +424,7 @@ macro_rules! error_type_var_body_emit {
         }
 
         error_type_var_body_emit! {
-            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr $($tail)*
+            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr, $es_tr $($tail)*
         }
     };
 
@@ -160,7 +432,7 @@ macro_rules! error_type_var_body_emit {
     desc () clause.
     */
     (
-        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident,
+        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
         desc ()
         $($tail:tt)*
     ) => {
@@ -171,7 +443,7 @@ macro_rules! error_type_var_body_emit {
         }
 
         error_type_var_body_emit! {
-            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr $($tail)*
+            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr, $es_tr $($tail)*
         }
     };
 
@@ -179,7 +451,7 @@ macro_rules! error_type_var_body_emit {
     desc ((arg) expr) clause.
     */
     (
-        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident,
+        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
         desc (($desc_arg:ident) $desc_expr:expr)
         $($tail:tt)*
     ) => {
@@ -189,9 +461,9 @@ macro_rules! error_type_var_body_emit {
                 $desc_expr
             }
         }
-        
+
         error_type_var_body_emit! {
-            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr $($tail)*
+            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr, $es_tr $($tail)*
         }
     };
 
@@ -199,7 +471,7 @@ macro_rules! error_type_var_body_emit {
     cause () clause.
     */
     (
-        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident,
+        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
         cause ()
         $($tail:tt)*
     ) => {
@@ -209,8 +481,14 @@ macro_rules! error_type_var_body_emit {
             }
         }
 
+        impl<'a> $es_tr<'a> for (&'a $err_name, &'a $var_ty) {
+            fn error_source(&self) -> ::std::option::Option<&'a (::std::error::Error + 'static)> {
+                None
+            }
+        }
+
         error_type_var_body_emit! {
-            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr $($tail)*
+            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr, $es_tr $($tail)*
         }
     };
 
@@ -218,7 +496,7 @@ macro_rules! error_type_var_body_emit {
     cause ((arg) expr) clause.
     */
     (
-        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident,
+        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
         cause (($cl_arg:ident) $cl_expr:expr)
         $($tail:tt)*
     ) => {
@@ -228,9 +506,46 @@ macro_rules! error_type_var_body_emit {
                 $cl_expr
             }
         }
-        
+
+        impl<'a> $es_tr<'a> for (&'a $err_name, &'a $var_ty) {
+            fn error_source(&self) -> ::std::option::Option<&'a (::std::error::Error + 'static)> {
+                let $cl_arg = self.1;
+                $cl_expr
+            }
+        }
+
+        error_type_var_body_emit! {
+            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr, $es_tr $($tail)*
+        }
+    };
+
+    /*
+    Automatic `cause;` clause: forwards to the payload's own `cause()`.
+    That returns `Option<&Error>` with no `'static` bound, so it can drive
+    `error_cause` but not `error_source` (which requires one); `source`
+    forwards to the payload's own `source()` instead of reusing the same
+    expression.
+    */
+    (
+        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
+        cause (auto ($cl_arg:ident) $cl_expr:expr)
+        $($tail:tt)*
+    ) => {
+        impl<'a> $ec_tr<'a> for (&'a $err_name, &'a $var_ty) {
+            fn error_cause(&self) -> ::std::option::Option<&'a ::std::error::Error> {
+                let $cl_arg = self.1;
+                $cl_expr
+            }
+        }
+
+        impl<'a> $es_tr<'a> for (&'a $err_name, &'a $var_ty) {
+            fn error_source(&self) -> ::std::option::Option<&'a (::std::error::Error + 'static)> {
+                ::std::error::Error::source(self.1)
+            }
+        }
+
         error_type_var_body_emit! {
-            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr $($tail)*
+            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr, $es_tr $($tail)*
         }
     };
 
@@ -238,7 +553,7 @@ macro_rules! error_type_var_body_emit {
     from ((arg: ty) expr) clause.
     */
     (
-        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident,
+        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
         from ($(($cl_arg:ident: $cl_ty:ty) $cl_expr:expr);*)
         $($tail:tt)*
     ) => {
@@ -251,7 +566,60 @@ macro_rules! error_type_var_body_emit {
         )*
 
         error_type_var_body_emit! {
-            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr $($tail)*
+            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr, $es_tr $($tail)*
+        }
+    };
+
+    /*
+    flatten () clause: no flattening, the payload is stored as-is.
+    */
+    (
+        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
+        flatten ()
+        $($tail:tt)*
+    ) => {
+        impl ::std::convert::From<$var_ty> for $err_name {
+            fn from(value: $var_ty) -> $err_name {
+                $err_name::$var_name(value)
+            }
+        }
+
+        error_type_var_body_emit! {
+            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr, $es_tr $($tail)*
+        }
+    };
+
+    /*
+    flatten (SubVariant, ..) clause: `$var_ty` is itself another
+    `error_type!`-generated enum; instead of nesting the whole sub-error in
+    this variant, match each of its listed variants and re-wrap the payload
+    by dispatching to whichever `From` impl on `$err_name` already accepts
+    it.  This is how smaller error types compose into larger ones without
+    adding a level of nesting.
+
+    `$var_ty` arrives as an opaque `:ty` fragment rather than source
+    tokens, so a qualified path built from it (`<$var_ty>::$sub_var(v)`)
+    can't be used as a match pattern -- that's only stable when the type
+    is written as a literal path in source (rust-lang/rust#86935).
+    Bringing the variants into scope with `use $var_ty::*;` first and
+    matching on the bare variant names sidesteps the issue.
+    */
+    (
+        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
+        flatten ($($sub_var:ident),+)
+        $($tail:tt)*
+    ) => {
+        impl ::std::convert::From<$var_ty> for $err_name {
+            fn from(value: $var_ty) -> $err_name {
+                use $var_ty::*;
+                match value {
+                    $($sub_var(v) => ::std::convert::From::from(v),)+
+                }
+            }
+        }
+
+        error_type_var_body_emit! {
+            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr, $es_tr $($tail)*
         }
     };
 }
@@ -263,12 +631,12 @@ macro_rules! error_type_var_body {
     Base case: no more clauses.
     */
     (
-        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident,
-        $disp:tt, $desc:tt, $cause:tt, $from:tt; {}
+        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
+        $disp:tt, $desc:tt, $cause:tt, $from:tt, $context:tt, $flatten:tt; {}
     ) => {
         error_type_var_body_emit! {
-            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr,
-            disp $disp, desc $desc, cause $cause, from $from
+            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr, $es_tr,
+            disp $disp, desc $desc, cause $cause, from $from, context $context, flatten $flatten
         }
     };
 
@@ -276,224 +644,1050 @@ macro_rules! error_type_var_body {
     disp (arg, fmt) expr;
     */
     (
-        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident,
-        $disp:tt, $desc:tt, $cause:tt, $from:tt; {
+        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
+        $disp:tt, $desc:tt, $cause:tt, $from:tt, $context:tt, $flatten:tt; {
             disp ($cl_arg:ident, $cl_fmt:ident) $cl_body:expr;
             $($tail:tt)*
         }
     ) => {
         error_type_var_body! {
-            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr,
-            (($cl_arg, $cl_fmt) $cl_body), $desc, $cause, $from;
+            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr, $es_tr,
+            (($cl_arg, $cl_fmt) $cl_body), $desc, $cause, $from, $context, $flatten;
             {$($tail)*}
         }
     };
 
     /*
-    desc (arg) expr;
+    disp (arg) "format string", args...; -- sugar for disp (arg, fmt) write!(fmt, "format string", args...);
+
+    Like the bare form below, but names the payload binding so args can
+    refer to it.  `$cl_arg` is forwarded as a metavariable rather than
+    the call site writing `self.1` directly, since a literal `self`
+    spelled at the call site can't resolve against the `self` bound by
+    the generated method -- macro hygiene gives the two their own,
+    unrelated identities despite the shared spelling.
     */
     (
-        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident,
-        $disp:tt, $desc:tt, $cause:tt, $from:tt; {
-            desc ($cl_arg:ident) $cl_body:expr;
+        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
+        $disp:tt, $desc:tt, $cause:tt, $from:tt, $context:tt, $flatten:tt; {
+            disp ($cl_arg:ident) $cl_fmt:expr $(, $cl_extra:expr)*;
             $($tail:tt)*
         }
     ) => {
         error_type_var_body! {
-            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr,
-            $disp, (($cl_arg) $cl_body), $cause, $from;
+            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr, $es_tr,
+            (($cl_arg, fmt) write!(fmt, $cl_fmt $(, $cl_extra)*)), $desc, $cause, $from, $context, $flatten;
             {$($tail)*}
         }
     };
 
     /*
-    cause (arg) expr;
+    disp "format string", args...; -- sugar for disp (_e, fmt) write!(fmt, "format string", args...);
+
+    The sugar doesn't bind a name for the payload, so if an argument
+    needs to reach it, use the `disp (arg) "...", args...;` form above
+    instead.
     */
     (
-        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident,
-        $disp:tt, $desc:tt, $cause:tt, $from:tt; {
-            cause ($cl_arg:ident) $cl_body:expr;
+        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
+        $disp:tt, $desc:tt, $cause:tt, $from:tt, $context:tt, $flatten:tt; {
+            disp $cl_fmt:expr $(, $cl_arg:expr)*;
             $($tail:tt)*
         }
     ) => {
         error_type_var_body! {
-            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr,
-            $disp, $desc, (($cl_arg) $cl_body), $from;
+            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr, $es_tr,
+            ((_e, fmt) write!(fmt, $cl_fmt $(, $cl_arg)*)), $desc, $cause, $from, $context, $flatten;
             {$($tail)*}
         }
     };
 
     /*
-    cause;
+    desc (arg) expr;
     */
     (
-        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident,
-        $disp:tt, $desc:tt, $cause:tt, $from:tt; {
-            cause;
+        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
+        $disp:tt, $desc:tt, $cause:tt, $from:tt, $context:tt, $flatten:tt; {
+            desc ($cl_arg:ident) $cl_body:expr;
             $($tail:tt)*
         }
     ) => {
         error_type_var_body! {
-            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr,
-            $disp, $desc, ((e) ::std::error::Error::cause(e)), $from;
+            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr, $es_tr,
+            $disp, (($cl_arg) $cl_body), $cause, $from, $context, $flatten;
             {$($tail)*}
         }
     };
 
     /*
-    from (arg: Ty) expr; (first)
+    desc "literal"; -- sugar for desc (_e) "literal";
     */
     (
-        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident,
-        $disp:tt, $desc:tt, $cause:tt, (); {
-            from ($cl_arg:ident: $cl_ty:ty) $cl_body:expr;
+        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
+        $disp:tt, $desc:tt, $cause:tt, $from:tt, $context:tt, $flatten:tt; {
+            desc $cl_lit:expr;
             $($tail:tt)*
         }
     ) => {
         error_type_var_body! {
-            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr,
-            $disp, $desc, $cause, (($cl_arg: $cl_ty) $cl_body);
+            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr, $es_tr,
+            $disp, ((_e) $cl_lit), $cause, $from, $context, $flatten;
             {$($tail)*}
         }
     };
 
     /*
-    from (arg: Ty) expr; (not first)
+    cause (arg) expr;
     */
     (
-        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident,
-        $disp:tt, $desc:tt, $cause:tt, ($($from:tt)*); {
-            from ($cl_arg:ident: $cl_ty:ty) $cl_body:expr;
+        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
+        $disp:tt, $desc:tt, $cause:tt, $from:tt, $context:tt, $flatten:tt; {
+            cause ($cl_arg:ident) $cl_body:expr;
             $($tail:tt)*
         }
     ) => {
         error_type_var_body! {
-            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr,
-            $disp, $desc, $cause, (($cl_arg: $cl_ty) $cl_body; $($from)*);
+            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr, $es_tr,
+            $disp, $desc, (($cl_arg) $cl_body), $from, $context, $flatten;
             {$($tail)*}
         }
     };
-}
 
-#[doc(hidden)]
-#[macro_export]
-macro_rules! error_type_impl {
+    /*
+    cause;
+    */
     (
-        $(#[$($derive_tts:tt)*])*
-        enum $err_name:ident {
-            $($var_name:ident($var_ty:ty) $var_body:tt),+
-            $(,)*
+        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
+        $disp:tt, $desc:tt, $cause:tt, $from:tt, $context:tt, $flatten:tt; {
+            cause;
+            $($tail:tt)*
         }
     ) => {
-        $(
-            impl ::std::convert::From<$var_ty> for $err_name {
-                fn from(value: $var_ty) -> $err_name {
-                    $err_name::$var_name(value)
-                }
-            }
-        )+
-        
-        impl ::std::fmt::Display for $err_name {
-            fn fmt(&self, fmt: &mut ::std::fmt::Formatter) -> ::std::result::Result<(), ::std::fmt::Error> {
-                match *self {
-                    $(
-                        $err_name::$var_name(ref v) => (self, v).error_fmt(fmt)
-                    ),+
-                }
-            }
+        error_type_var_body! {
+            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr, $es_tr,
+            $disp, $desc, (auto (e) ::std::error::Error::cause(e)), $from, $context, $flatten;
+            {$($tail)*}
         }
+    };
 
-        pub trait ErrorDisplay {
-            fn error_fmt(&self, &mut ::std::fmt::Formatter) -> ::std::result::Result<(), ::std::fmt::Error>;
+    /*
+    context;
+    */
+    (
+        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
+        $disp:tt, $desc:tt, $cause:tt, $from:tt, $context:tt, $flatten:tt; {
+            context;
+            $($tail:tt)*
         }
-
-        pub trait ErrorDescription<'a> {
-            fn error_desc(&self) -> &'a str;
+    ) => {
+        error_type_var_body! {
+            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr, $es_tr,
+            $disp, $desc, $cause, $from, (yes), $flatten;
+            {$($tail)*}
+        }
+    };
+
+    /*
+    flatten (SubVariant, ..);
+    */
+    (
+        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
+        $disp:tt, $desc:tt, $cause:tt, $from:tt, $context:tt, $flatten:tt; {
+            flatten ($($sub_var:ident),+);
+            $($tail:tt)*
+        }
+    ) => {
+        error_type_var_body! {
+            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr, $es_tr,
+            $disp, $desc, $cause, $from, $context, ($($sub_var),+);
+            {$($tail)*}
+        }
+    };
+
+    /*
+    from (arg: Ty) expr; (first)
+    */
+    (
+        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
+        $disp:tt, $desc:tt, $cause:tt, (), $context:tt, $flatten:tt; {
+            from ($cl_arg:ident: $cl_ty:ty) $cl_body:expr;
+            $($tail:tt)*
+        }
+    ) => {
+        error_type_var_body! {
+            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr, $es_tr,
+            $disp, $desc, $cause, (($cl_arg: $cl_ty) $cl_body), $context, $flatten;
+            {$($tail)*}
+        }
+    };
+
+    /*
+    from (arg: Ty) expr; (not first)
+    */
+    (
+        $err_name:ident, $var_name:ident, $var_ty:ty, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
+        $disp:tt, $desc:tt, $cause:tt, ($($from:tt)*), $context:tt, $flatten:tt; {
+            from ($cl_arg:ident: $cl_ty:ty) $cl_body:expr;
+            $($tail:tt)*
+        }
+    ) => {
+        error_type_var_body! {
+            $err_name, $var_name, $var_ty, $edi_tr, $ede_tr, $ec_tr, $es_tr,
+            $disp, $desc, $cause, (($cl_arg: $cl_ty) $cl_body; $($from)*), $context, $flatten;
+            {$($tail)*}
+        }
+    };
+}
+
+/*
+Clause emission for unitary (payload-less) variants.  This mirrors
+`error_type_var_body_emit!`, but since there is no payload to pair `self`
+with, it targets `(&'a $err_name, &'a $var_name)` instead, where
+`$var_name` is a zero-sized marker struct named after the variant (see
+`error_type_impl_step!`) -- this keeps each unit variant's impls on a
+distinct type, rather than every unit variant in the same enum colliding
+on a shared `(&'a $err_name,)`.  Note there is no default `disp ()`/
+`desc ()` clause here: without a payload to forward to, both must always
+be given explicitly.
+*/
+#[doc(hidden)]
+#[macro_export]
+macro_rules! error_type_var_body_unit_emit {
+    /*
+    Nothing left.
+    */
+    (
+        $err_name:ident, $var_name:ident, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident
+    ) => {
+        // Done.
+    };
+
+    /*
+    disp ((arg, fmt) expr) clause.
+    */
+    (
+        $err_name:ident, $var_name:ident, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
+        disp (($disp_arg:ident, $disp_fmt:ident) $disp_expr:expr)
+        $($tail:tt)*
+    ) => {
+        impl<'a> $edi_tr for (&'a $err_name, &'a $var_name) {
+            fn error_fmt(&self, fmt: &mut ::std::fmt::Formatter) -> ::std::result::Result<(), ::std::fmt::Error> {
+                let $disp_arg = self.0;
+                let $disp_fmt = fmt;
+                $disp_expr
+            }
+        }
+
+        error_type_var_body_unit_emit! {
+            $err_name, $var_name, $edi_tr, $ede_tr, $ec_tr, $es_tr $($tail)*
+        }
+    };
+
+    /*
+    desc ((arg) expr) clause.
+    */
+    (
+        $err_name:ident, $var_name:ident, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
+        desc (($desc_arg:ident) $desc_expr:expr)
+        $($tail:tt)*
+    ) => {
+        impl<'a> $ede_tr<'a> for (&'a $err_name, &'a $var_name) {
+            fn error_desc(&self) -> &'a str {
+                let $desc_arg = self.0;
+                $desc_expr
+            }
+        }
+
+        error_type_var_body_unit_emit! {
+            $err_name, $var_name, $edi_tr, $ede_tr, $ec_tr, $es_tr $($tail)*
+        }
+    };
+
+    /*
+    cause () clause.
+    */
+    (
+        $err_name:ident, $var_name:ident, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
+        cause ()
+        $($tail:tt)*
+    ) => {
+        impl<'a> $ec_tr<'a> for (&'a $err_name, &'a $var_name) {
+            fn error_cause(&self) -> ::std::option::Option<&'a ::std::error::Error> {
+                None
+            }
+        }
+
+        impl<'a> $es_tr<'a> for (&'a $err_name, &'a $var_name) {
+            fn error_source(&self) -> ::std::option::Option<&'a (::std::error::Error + 'static)> {
+                None
+            }
+        }
+
+        error_type_var_body_unit_emit! {
+            $err_name, $var_name, $edi_tr, $ede_tr, $ec_tr, $es_tr $($tail)*
+        }
+    };
+
+    /*
+    cause ((arg) expr) clause.
+    */
+    (
+        $err_name:ident, $var_name:ident, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
+        cause (($cl_arg:ident) $cl_expr:expr)
+        $($tail:tt)*
+    ) => {
+        impl<'a> $ec_tr<'a> for (&'a $err_name, &'a $var_name) {
+            fn error_cause(&self) -> ::std::option::Option<&'a ::std::error::Error> {
+                let $cl_arg = self.0;
+                $cl_expr
+            }
+        }
+
+        impl<'a> $es_tr<'a> for (&'a $err_name, &'a $var_name) {
+            fn error_source(&self) -> ::std::option::Option<&'a (::std::error::Error + 'static)> {
+                let $cl_arg = self.0;
+                $cl_expr
+            }
+        }
+
+        error_type_var_body_unit_emit! {
+            $err_name, $var_name, $edi_tr, $ede_tr, $ec_tr, $es_tr $($tail)*
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! error_type_var_body_unit {
+    /*
+    Base case: no more clauses.
+    */
+    (
+        $err_name:ident, $var_name:ident, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
+        $disp:tt, $desc:tt, $cause:tt; {}
+    ) => {
+        error_type_var_body_unit_emit! {
+            $err_name, $var_name, $edi_tr, $ede_tr, $ec_tr, $es_tr,
+            disp $disp, desc $desc, cause $cause
+        }
+    };
+
+    /*
+    disp (arg, fmt) expr;
+    */
+    (
+        $err_name:ident, $var_name:ident, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
+        $disp:tt, $desc:tt, $cause:tt; {
+            disp ($cl_arg:ident, $cl_fmt:ident) $cl_body:expr;
+            $($tail:tt)*
+        }
+    ) => {
+        error_type_var_body_unit! {
+            $err_name, $var_name, $edi_tr, $ede_tr, $ec_tr, $es_tr,
+            (($cl_arg, $cl_fmt) $cl_body), $desc, $cause;
+            {$($tail)*}
+        }
+    };
+
+    /*
+    disp "format string", args...; -- sugar for disp (_e, fmt) write!(fmt, "format string", args...);
+    */
+    (
+        $err_name:ident, $var_name:ident, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
+        $disp:tt, $desc:tt, $cause:tt; {
+            disp $cl_fmt:expr $(, $cl_arg:expr)*;
+            $($tail:tt)*
+        }
+    ) => {
+        error_type_var_body_unit! {
+            $err_name, $var_name, $edi_tr, $ede_tr, $ec_tr, $es_tr,
+            ((_e, fmt) write!(fmt, $cl_fmt $(, $cl_arg)*)), $desc, $cause;
+            {$($tail)*}
+        }
+    };
+
+    /*
+    desc (arg) expr;
+    */
+    (
+        $err_name:ident, $var_name:ident, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
+        $disp:tt, $desc:tt, $cause:tt; {
+            desc ($cl_arg:ident) $cl_body:expr;
+            $($tail:tt)*
+        }
+    ) => {
+        error_type_var_body_unit! {
+            $err_name, $var_name, $edi_tr, $ede_tr, $ec_tr, $es_tr,
+            $disp, (($cl_arg) $cl_body), $cause;
+            {$($tail)*}
+        }
+    };
+
+    /*
+    desc "literal"; -- sugar for desc (_e) "literal";
+    */
+    (
+        $err_name:ident, $var_name:ident, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
+        $disp:tt, $desc:tt, $cause:tt; {
+            desc $cl_lit:expr;
+            $($tail:tt)*
+        }
+    ) => {
+        error_type_var_body_unit! {
+            $err_name, $var_name, $edi_tr, $ede_tr, $ec_tr, $es_tr,
+            $disp, ((_e) $cl_lit), $cause;
+            {$($tail)*}
+        }
+    };
+
+    /*
+    cause (arg) expr;
+    */
+    (
+        $err_name:ident, $var_name:ident, $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
+        $disp:tt, $desc:tt, $cause:tt; {
+            cause ($cl_arg:ident) $cl_body:expr;
+            $($tail:tt)*
+        }
+    ) => {
+        error_type_var_body_unit! {
+            $err_name, $var_name, $edi_tr, $ede_tr, $ec_tr, $es_tr,
+            $disp, $desc, (($cl_arg) $cl_body);
+            {$($tail)*}
+        }
+    };
+}
+
+/*
+Clause emission for struct variants (several named fields).  Like
+`error_type_var_body_unit_emit!`, this targets `(&'a $err_name, &'a
+$var_name)` rather than a single-element tuple, using the same per-variant
+marker struct, since there's no single payload to pair `self` with and
+every unit/struct variant would otherwise collide on the same impl target.
+Unlike the unit case, each method body destructures the variant's fields
+by name before evaluating the clause expression, so the clause can refer
+to them directly.  There is no default `disp ()`/`desc ()` clause here
+either: both must always be given explicitly.  Note `disp` only binds the
+formatter, since the fields are already in scope by name.
+*/
+#[doc(hidden)]
+#[macro_export]
+macro_rules! error_type_var_body_struct_emit {
+    /*
+    Nothing left.
+    */
+    (
+        $err_name:ident, $var_name:ident, ($($field_name:ident),*), $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident
+    ) => {
+        // Done.
+    };
+
+    /*
+    disp ((fmt) expr) clause.
+    */
+    (
+        $err_name:ident, $var_name:ident, ($($field_name:ident),*), $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
+        disp (($disp_fmt:ident) $disp_expr:expr)
+        $($tail:tt)*
+    ) => {
+        impl<'a> $edi_tr for (&'a $err_name, &'a $var_name) {
+            fn error_fmt(&self, fmt: &mut ::std::fmt::Formatter) -> ::std::result::Result<(), ::std::fmt::Error> {
+                match *self.0 {
+                    $err_name::$var_name { $(ref $field_name,)* .. } => {
+                        let $disp_fmt = fmt;
+                        $disp_expr
+                    },
+                    _ => unreachable!(),
+                }
+            }
+        }
+
+        error_type_var_body_struct_emit! {
+            $err_name, $var_name, ($($field_name),*), $edi_tr, $ede_tr, $ec_tr, $es_tr $($tail)*
+        }
+    };
+
+    /*
+    desc ((arg) expr) clause.
+    */
+    (
+        $err_name:ident, $var_name:ident, ($($field_name:ident),*), $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
+        desc (($desc_arg:ident) $desc_expr:expr)
+        $($tail:tt)*
+    ) => {
+        impl<'a> $ede_tr<'a> for (&'a $err_name, &'a $var_name) {
+            fn error_desc(&self) -> &'a str {
+                match *self.0 {
+                    $err_name::$var_name { $(ref $field_name,)* .. } => {
+                        let $desc_arg = self.0;
+                        $desc_expr
+                    },
+                    _ => unreachable!(),
+                }
+            }
+        }
+
+        error_type_var_body_struct_emit! {
+            $err_name, $var_name, ($($field_name),*), $edi_tr, $ede_tr, $ec_tr, $es_tr $($tail)*
+        }
+    };
+
+    /*
+    cause () clause.
+    */
+    (
+        $err_name:ident, $var_name:ident, ($($field_name:ident),*), $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
+        cause ()
+        $($tail:tt)*
+    ) => {
+        impl<'a> $ec_tr<'a> for (&'a $err_name, &'a $var_name) {
+            fn error_cause(&self) -> ::std::option::Option<&'a ::std::error::Error> {
+                None
+            }
+        }
+
+        impl<'a> $es_tr<'a> for (&'a $err_name, &'a $var_name) {
+            fn error_source(&self) -> ::std::option::Option<&'a (::std::error::Error + 'static)> {
+                None
+            }
+        }
+
+        error_type_var_body_struct_emit! {
+            $err_name, $var_name, ($($field_name),*), $edi_tr, $ede_tr, $ec_tr, $es_tr $($tail)*
+        }
+    };
+
+    /*
+    cause ((arg) expr) clause.
+    */
+    (
+        $err_name:ident, $var_name:ident, ($($field_name:ident),*), $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
+        cause (($cl_arg:ident) $cl_expr:expr)
+        $($tail:tt)*
+    ) => {
+        impl<'a> $ec_tr<'a> for (&'a $err_name, &'a $var_name) {
+            fn error_cause(&self) -> ::std::option::Option<&'a ::std::error::Error> {
+                match *self.0 {
+                    $err_name::$var_name { $(ref $field_name,)* .. } => {
+                        let $cl_arg = self.0;
+                        $cl_expr
+                    },
+                    _ => unreachable!(),
+                }
+            }
         }
 
-        pub trait ErrorCause<'a> {
-            fn error_cause(&self) -> ::std::option::Option<&'a ::std::error::Error>;
+        impl<'a> $es_tr<'a> for (&'a $err_name, &'a $var_name) {
+            fn error_source(&self) -> ::std::option::Option<&'a (::std::error::Error + 'static)> {
+                match *self.0 {
+                    $err_name::$var_name { $(ref $field_name,)* .. } => {
+                        let $cl_arg = self.0;
+                        $cl_expr
+                    },
+                    _ => unreachable!(),
+                }
+            }
+        }
+
+        error_type_var_body_struct_emit! {
+            $err_name, $var_name, ($($field_name),*), $edi_tr, $ede_tr, $ec_tr, $es_tr $($tail)*
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! error_type_var_body_struct {
+    /*
+    Base case: no more clauses.
+    */
+    (
+        $err_name:ident, $var_name:ident, ($($field_name:ident),*), $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
+        $disp:tt, $desc:tt, $cause:tt; {}
+    ) => {
+        error_type_var_body_struct_emit! {
+            $err_name, $var_name, ($($field_name),*), $edi_tr, $ede_tr, $ec_tr, $es_tr,
+            disp $disp, desc $desc, cause $cause
+        }
+    };
+
+    /*
+    disp (fmt) expr;
+    */
+    (
+        $err_name:ident, $var_name:ident, ($($field_name:ident),*), $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
+        $disp:tt, $desc:tt, $cause:tt; {
+            disp ($cl_fmt:ident) $cl_body:expr;
+            $($tail:tt)*
+        }
+    ) => {
+        error_type_var_body_struct! {
+            $err_name, $var_name, ($($field_name),*), $edi_tr, $ede_tr, $ec_tr, $es_tr,
+            (($cl_fmt) $cl_body), $desc, $cause;
+            {$($tail)*}
+        }
+    };
+
+    /*
+    disp "format string", args...; -- sugar for disp (fmt) write!(fmt, "format string", args...);
+    */
+    (
+        $err_name:ident, $var_name:ident, ($($field_name:ident),*), $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
+        $disp:tt, $desc:tt, $cause:tt; {
+            disp $cl_fmt:expr $(, $cl_arg:expr)*;
+            $($tail:tt)*
+        }
+    ) => {
+        error_type_var_body_struct! {
+            $err_name, $var_name, ($($field_name),*), $edi_tr, $ede_tr, $ec_tr, $es_tr,
+            ((fmt) write!(fmt, $cl_fmt $(, $cl_arg)*)), $desc, $cause;
+            {$($tail)*}
+        }
+    };
+
+    /*
+    desc (arg) expr;
+    */
+    (
+        $err_name:ident, $var_name:ident, ($($field_name:ident),*), $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
+        $disp:tt, $desc:tt, $cause:tt; {
+            desc ($cl_arg:ident) $cl_body:expr;
+            $($tail:tt)*
+        }
+    ) => {
+        error_type_var_body_struct! {
+            $err_name, $var_name, ($($field_name),*), $edi_tr, $ede_tr, $ec_tr, $es_tr,
+            $disp, (($cl_arg) $cl_body), $cause;
+            {$($tail)*}
+        }
+    };
+
+    /*
+    desc "literal"; -- sugar for desc (_e) "literal";
+    */
+    (
+        $err_name:ident, $var_name:ident, ($($field_name:ident),*), $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
+        $disp:tt, $desc:tt, $cause:tt; {
+            desc $cl_lit:expr;
+            $($tail:tt)*
+        }
+    ) => {
+        error_type_var_body_struct! {
+            $err_name, $var_name, ($($field_name),*), $edi_tr, $ede_tr, $ec_tr, $es_tr,
+            $disp, ((_e) $cl_lit), $cause;
+            {$($tail)*}
+        }
+    };
+
+    /*
+    cause (arg) expr;
+    */
+    (
+        $err_name:ident, $var_name:ident, ($($field_name:ident),*), $edi_tr:ident, $ede_tr:ident, $ec_tr:ident, $es_tr:ident,
+        $disp:tt, $desc:tt, $cause:tt; {
+            cause ($cl_arg:ident) $cl_body:expr;
+            $($tail:tt)*
+        }
+    ) => {
+        error_type_var_body_struct! {
+            $err_name, $var_name, ($($field_name),*), $edi_tr, $ede_tr, $ec_tr, $es_tr,
+            $disp, $desc, (($cl_arg) $cl_body);
+            {$($tail)*}
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! error_type_impl_step {
+    /*
+    Base case: no more variants.
+
+    `$self_tok`/`$fmt_tok` are threaded through from the single place they're
+    first written (`error_type_impl!`'s invocation of this macro, below)
+    rather than being re-spelled as bare `self`/`fmt` at each recursion step.
+    Macro hygiene gives every occurrence of a literal `self`/`fmt` written in
+    a *different* macro expansion its own identity, even if the spelling is
+    identical -- so a `self`/`fmt` written inside one of the per-variant
+    rules below wouldn't resolve against the `self`/`fmt` parameters written
+    here. Forwarding the original tokens as `$self_tok`/`$fmt_tok` metavariables
+    keeps every use tied to that one original expansion.
+    */
+    (
+        $err_name:ident; $self_tok:tt, $fmt_tok:ident;
+        ($($disp_arms:tt)*); ($($desc_arms:tt)*); ($($cause_arms:tt)*); ($($source_arms:tt)*);
+    ) => {
+        impl ::std::fmt::Display for $err_name {
+            fn fmt(&$self_tok, $fmt_tok: &mut ::std::fmt::Formatter) -> ::std::result::Result<(), ::std::fmt::Error> {
+                match *$self_tok {
+                    $($disp_arms)*
+                }
+            }
         }
-        
+
         impl ::std::error::Error for $err_name {
-            fn description(&self) -> &str {
-                use self::ErrorDescription;
-                match *self {
-                    $(
-                        $err_name::$var_name(ref v) => (self, v).error_desc()
-                    ),+
+            fn description(&$self_tok) -> &str {
+                match *$self_tok {
+                    $($desc_arms)*
                 }
             }
-            
-            fn cause(&self) -> ::std::option::Option<&::std::error::Error> {
-                use self::ErrorCause;
-                match *self {
-                    $(
-                        $err_name::$var_name(ref v) => (self, v).error_cause()
-                    ),+
+
+            fn cause(&$self_tok) -> ::std::option::Option<&::std::error::Error> {
+                match *$self_tok {
+                    $($cause_arms)*
                 }
             }
-        }
-        
-        $(
-            error_type_var_body! {
-                $err_name, $var_name, $var_ty,
-                ErrorDisplay, ErrorDescription, ErrorCause,
-                (), (), (), ();
-                $var_body
+
+            fn source(&$self_tok) -> ::std::option::Option<&(::std::error::Error + 'static)> {
+                match *$self_tok {
+                    $($source_arms)*
+                }
             }
-        )+
+        }
+    };
+
+    /*
+    Tuple variant, more remain.
+    */
+    (
+        $err_name:ident; $self_tok:tt, $fmt_tok:ident;
+        ($($disp_arms:tt)*); ($($desc_arms:tt)*); ($($cause_arms:tt)*); ($($source_arms:tt)*);
+        t($var_name:ident ($var_ty:ty) $var_body:tt), $($tail:tt)*
+    ) => {
+        error_type_var_body! {
+            $err_name, $var_name, $var_ty,
+            ErrorDisplay, ErrorDescription, ErrorCause, ErrorSource,
+            (), (), (), (), (), ();
+            $var_body
+        }
+
+        error_type_impl_step! {
+            $err_name; $self_tok, $fmt_tok;
+            ($($disp_arms)* $err_name::$var_name(ref v) => ($self_tok, v).error_fmt($fmt_tok),);
+            ($($desc_arms)* $err_name::$var_name(ref v) => ($self_tok, v).error_desc(),);
+            ($($cause_arms)* $err_name::$var_name(ref v) => ($self_tok, v).error_cause(),);
+            ($($source_arms)* $err_name::$var_name(ref v) => ($self_tok, v).error_source(),);
+            $($tail)*
+        }
+    };
+
+    /*
+    Tuple variant, last one.
+    */
+    (
+        $err_name:ident; $self_tok:tt, $fmt_tok:ident;
+        ($($disp_arms:tt)*); ($($desc_arms:tt)*); ($($cause_arms:tt)*); ($($source_arms:tt)*);
+        t($var_name:ident ($var_ty:ty) $var_body:tt)
+    ) => {
+        error_type_impl_step! {
+            $err_name; $self_tok, $fmt_tok;
+            ($($disp_arms)*); ($($desc_arms)*); ($($cause_arms)*); ($($source_arms)*);
+            t($var_name($var_ty) $var_body),
+        }
+    };
+
+    /*
+    Unit variant, more remain.
+
+    Unit variants carry no payload, so there's nothing to pair with `self`
+    in the impl target -- but every unit variant in the same enum would
+    otherwise collide on the same empty-payload type.  A zero-sized marker
+    struct named after the variant (legal since variants and top-level
+    items live in separate namespaces) gives each unit variant its own
+    distinct impl target instead.
+    */
+    (
+        $err_name:ident; $self_tok:tt, $fmt_tok:ident;
+        ($($disp_arms:tt)*); ($($desc_arms:tt)*); ($($cause_arms:tt)*); ($($source_arms:tt)*);
+        u($var_name:ident $var_body:tt), $($tail:tt)*
+    ) => {
+        #[doc(hidden)]
+        struct $var_name;
+
+        error_type_var_body_unit! {
+            $err_name, $var_name,
+            ErrorDisplay, ErrorDescription, ErrorCause, ErrorSource,
+            (), (), ();
+            $var_body
+        }
+
+        error_type_impl_step! {
+            $err_name; $self_tok, $fmt_tok;
+            ($($disp_arms)* $err_name::$var_name => ($self_tok, &$var_name).error_fmt($fmt_tok),);
+            ($($desc_arms)* $err_name::$var_name => ($self_tok, &$var_name).error_desc(),);
+            ($($cause_arms)* $err_name::$var_name => ($self_tok, &$var_name).error_cause(),);
+            ($($source_arms)* $err_name::$var_name => ($self_tok, &$var_name).error_source(),);
+            $($tail)*
+        }
+    };
+
+    /*
+    Unit variant, last one.
+    */
+    (
+        $err_name:ident; $self_tok:tt, $fmt_tok:ident;
+        ($($disp_arms:tt)*); ($($desc_arms:tt)*); ($($cause_arms:tt)*); ($($source_arms:tt)*);
+        u($var_name:ident $var_body:tt)
+    ) => {
+        error_type_impl_step! {
+            $err_name; $self_tok, $fmt_tok;
+            ($($disp_arms)*); ($($desc_arms)*); ($($cause_arms)*); ($($source_arms)*);
+            u($var_name $var_body),
+        }
+    };
+
+    /*
+    Struct variant, more remain.
+
+    As with unit variants above, a zero-sized marker struct named after
+    the variant gives each struct/unit variant in the enum its own impl
+    target, rather than every one of them colliding on the same
+    payload-less type.
+    */
+    (
+        $err_name:ident; $self_tok:tt, $fmt_tok:ident;
+        ($($disp_arms:tt)*); ($($desc_arms:tt)*); ($($cause_arms:tt)*); ($($source_arms:tt)*);
+        s($var_name:ident { $($field_name:ident : $field_ty:ty),* $(,)* } $var_body:tt), $($tail:tt)*
+    ) => {
+        #[doc(hidden)]
+        struct $var_name;
+
+        error_type_var_body_struct! {
+            $err_name, $var_name, ($($field_name),*),
+            ErrorDisplay, ErrorDescription, ErrorCause, ErrorSource,
+            (), (), ();
+            $var_body
+        }
+
+        error_type_impl_step! {
+            $err_name; $self_tok, $fmt_tok;
+            ($($disp_arms)* $err_name::$var_name { .. } => ($self_tok, &$var_name).error_fmt($fmt_tok),);
+            ($($desc_arms)* $err_name::$var_name { .. } => ($self_tok, &$var_name).error_desc(),);
+            ($($cause_arms)* $err_name::$var_name { .. } => ($self_tok, &$var_name).error_cause(),);
+            ($($source_arms)* $err_name::$var_name { .. } => ($self_tok, &$var_name).error_source(),);
+            $($tail)*
+        }
+    };
+
+    /*
+    Struct variant, last one.
+    */
+    (
+        $err_name:ident; $self_tok:tt, $fmt_tok:ident;
+        ($($disp_arms:tt)*); ($($desc_arms:tt)*); ($($cause_arms:tt)*); ($($source_arms:tt)*);
+        s($var_name:ident { $($field_name:ident : $field_ty:ty),* $(,)* } $var_body:tt)
+    ) => {
+        error_type_impl_step! {
+            $err_name; $self_tok, $fmt_tok;
+            ($($disp_arms)*); ($($desc_arms)*); ($($cause_arms)*); ($($source_arms)*);
+            s($var_name { $($field_name: $field_ty),* } $var_body),
+        }
     };
 }
 
-/**
-Constructs a reasonably well-featured error type from a concise description.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! error_type_impl {
+    (
+        $(#[$($derive_tts:tt)*])*
+        enum $err_name:ident {
+            $($variants:tt)*
+        }
+    ) => {
+        pub trait ErrorDisplay {
+            fn error_fmt(&self, &mut ::std::fmt::Formatter) -> ::std::result::Result<(), ::std::fmt::Error>;
+        }
 
-For details, see the crate documentation.
+        pub trait ErrorDescription<'a> {
+            fn error_desc(&self) -> &'a str;
+        }
+
+        pub trait ErrorCause<'a> {
+            fn error_cause(&self) -> ::std::option::Option<&'a ::std::error::Error>;
+        }
+
+        pub trait ErrorSource<'a> {
+            fn error_source(&self) -> ::std::option::Option<&'a (::std::error::Error + 'static)>;
+        }
+
+        error_type_impl_step! {
+            $err_name; self, fmt;
+            (); (); (); ();
+            $($variants)*
+        }
+    };
+}
+
+/*
+Walks the raw variant list one variant at a time, since a variant may be
+a tuple-like `Name(Ty) { .. }`, a struct-like `Name { field: Ty, .. } { .. }`
+or a unitary `Name { .. }`, and those shapes can't be captured by a single
+repetition.  Builds up the plain enum body (for `error_type_as_item!`) and a
+tagged variant list -- `t(..)` for tuple variants, `s(..)` for struct ones,
+`u(..)` for unitary ones -- for `error_type_impl!`.
 */
+#[doc(hidden)]
 #[macro_export]
-macro_rules! error_type {
+macro_rules! error_type_variants {
+    /*
+    Base case: nothing left.
+    */
     (
         $(#[$($derive_tts:tt)*])*
-        pub enum $err_name:ident {
-            $($var_name:ident($var_ty:ty) $var_body:tt),+
-            $(,)*
-        }
+        ($($item_prefix:tt)*) $err_name:ident;
+        ($($enum_items:tt)*); ($($tagged_vars:tt)*);
     ) => {
         error_type_as_item! {
             $(#[$($derive_tts)*])*
-            pub enum $err_name {
-                $($var_name($var_ty)),+
+            $($item_prefix)* $err_name {
+                $($enum_items)*
             }
         }
-        
+
         error_type_impl! {
             $(#[$($derive_tts)*])*
             enum $err_name {
-                $($var_name($var_ty) $var_body),+
+                $($tagged_vars)*
             }
         }
     };
 
+    /*
+    Tuple variant, more remain.
+    */
     (
         $(#[$($derive_tts:tt)*])*
-        enum $err_name:ident {
-            $($var_name:ident($var_ty:ty) $var_body:tt),+
-            $(,)*
+        ($($item_prefix:tt)*) $err_name:ident;
+        ($($enum_items:tt)*); ($($tagged_vars:tt)*);
+        $var_name:ident($var_ty:ty) $var_body:tt, $($tail:tt)*
+    ) => {
+        error_type_variants! {
+            $(#[$($derive_tts)*])*
+            ($($item_prefix)*) $err_name;
+            ($($enum_items)* $var_name($var_ty),); ($($tagged_vars)* t($var_name($var_ty) $var_body),);
+            $($tail)*
         }
+    };
+
+    /*
+    Tuple variant, last one.
+    */
+    (
+        $(#[$($derive_tts:tt)*])*
+        ($($item_prefix:tt)*) $err_name:ident;
+        ($($enum_items:tt)*); ($($tagged_vars:tt)*);
+        $var_name:ident($var_ty:ty) $var_body:tt
     ) => {
-        error_type_as_item! {
+        error_type_variants! {
             $(#[$($derive_tts)*])*
-            enum $err_name {
-                $($var_name($var_ty)),+
-            }
+            ($($item_prefix)*) $err_name;
+            ($($enum_items)*); ($($tagged_vars)*);
+            $var_name($var_ty) $var_body,
         }
-        
-        error_type_impl! {
+    };
+
+    /*
+    Struct variant, more remain.
+    */
+    (
+        $(#[$($derive_tts:tt)*])*
+        ($($item_prefix:tt)*) $err_name:ident;
+        ($($enum_items:tt)*); ($($tagged_vars:tt)*);
+        $var_name:ident { $($field_name:ident : $field_ty:ty),* $(,)* } $var_body:tt, $($tail:tt)*
+    ) => {
+        error_type_variants! {
             $(#[$($derive_tts)*])*
-            enum $err_name {
-                $($var_name($var_ty) $var_body),+
-            }
+            ($($item_prefix)*) $err_name;
+            ($($enum_items)* $var_name { $($field_name: $field_ty),* },);
+            ($($tagged_vars)* s($var_name { $($field_name: $field_ty),* } $var_body),);
+            $($tail)*
+        }
+    };
+
+    /*
+    Struct variant, last one.
+    */
+    (
+        $(#[$($derive_tts:tt)*])*
+        ($($item_prefix:tt)*) $err_name:ident;
+        ($($enum_items:tt)*); ($($tagged_vars:tt)*);
+        $var_name:ident { $($field_name:ident : $field_ty:ty),* $(,)* } $var_body:tt
+    ) => {
+        error_type_variants! {
+            $(#[$($derive_tts)*])*
+            ($($item_prefix)*) $err_name;
+            ($($enum_items)*); ($($tagged_vars)*);
+            $var_name { $($field_name: $field_ty),* } $var_body,
+        }
+    };
+
+    /*
+    Unitary variant, more remain.
+    */
+    (
+        $(#[$($derive_tts:tt)*])*
+        ($($item_prefix:tt)*) $err_name:ident;
+        ($($enum_items:tt)*); ($($tagged_vars:tt)*);
+        $var_name:ident $var_body:tt, $($tail:tt)*
+    ) => {
+        error_type_variants! {
+            $(#[$($derive_tts)*])*
+            ($($item_prefix)*) $err_name;
+            ($($enum_items)* $var_name,); ($($tagged_vars)* u($var_name $var_body),);
+            $($tail)*
+        }
+    };
+
+    /*
+    Unitary variant, last one.
+    */
+    (
+        $(#[$($derive_tts:tt)*])*
+        ($($item_prefix:tt)*) $err_name:ident;
+        ($($enum_items:tt)*); ($($tagged_vars:tt)*);
+        $var_name:ident $var_body:tt
+    ) => {
+        error_type_variants! {
+            $(#[$($derive_tts)*])*
+            ($($item_prefix)*) $err_name;
+            ($($enum_items)*); ($($tagged_vars)*);
+            $var_name $var_body,
+        }
+    };
+}
+
+/**
+Constructs a reasonably well-featured error type from a concise description.
+
+For details, see the crate documentation.
+*/
+#[macro_export]
+macro_rules! error_type {
+    (
+        $(#[$($derive_tts:tt)*])*
+        pub enum $err_name:ident {
+            $($body:tt)*
+        }
+    ) => {
+        error_type_variants! {
+            $(#[$($derive_tts)*])*
+            (pub enum) $err_name;
+            (); ();
+            $($body)*
+        }
+    };
+
+    (
+        $(#[$($derive_tts:tt)*])*
+        enum $err_name:ident {
+            $($body:tt)*
+        }
+    ) => {
+        error_type_variants! {
+            $(#[$($derive_tts)*])*
+            (enum) $err_name;
+            (); ();
+            $($body)*
         }
     };
 }
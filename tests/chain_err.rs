@@ -0,0 +1,30 @@
+/*!
+Checks the `context;` clause and the resulting `ResultExt::chain_err`.
+*/
+#[macro_use] extern crate error_type;
+
+error_type! {
+    #[derive(Debug)]
+    pub enum AppError {
+        Message((std::borrow::Cow<'static, str>, Box<std::error::Error>)) {
+            context;
+            disp (e, fmt) write!(fmt, "{}", e.0);
+            desc (e) &*e.0;
+            cause (e) Some(&*e.1);
+        },
+        Io(std::io::Error) { cause; },
+    }
+}
+
+#[test]
+fn test() {
+    use std::error::Error;
+
+    let io_err = std::io::Error::new(std::io::ErrorKind::Other, "oh no!");
+    let r: Result<(), std::io::Error> = Err(io_err);
+    let e: AppError = r.chain_err(|| "while doing a thing").unwrap_err();
+
+    assert_eq!(format!("{}", e), "while doing a thing");
+    assert_eq!(e.description(), "while doing a thing");
+    assert!(e.cause().is_some());
+}
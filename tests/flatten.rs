@@ -0,0 +1,42 @@
+/*!
+Checks the `flatten (SubVariant, ..);` clause, which lifts a nested
+`error_type!`-generated sub-error's payload directly into the matching
+variant of the super-error, instead of wrapping the whole sub-error.
+*/
+#[macro_use] extern crate error_type;
+
+mod other {
+    error_type! {
+        #[derive(Debug)]
+        pub enum SubError {
+            Io(std::io::Error) { cause; },
+            Parse(std::num::ParseIntError) { cause; },
+        }
+    }
+}
+
+error_type! {
+    #[derive(Debug)]
+    pub enum AppError {
+        Io(std::io::Error) { cause; },
+        Parse(std::num::ParseIntError) { cause; },
+        Sub(other::SubError) { flatten (Io, Parse); },
+    }
+}
+
+#[test]
+fn test() {
+    let sub: other::SubError = std::io::Error::new(std::io::ErrorKind::Other, "oh no!").into();
+    let e: AppError = sub.into();
+    match e {
+        AppError::Io(_) => (),
+        _ => panic!("expected AppError::Io"),
+    }
+
+    let sub: other::SubError = "not a number".parse::<i32>().unwrap_err().into();
+    let e: AppError = sub.into();
+    match e {
+        AppError::Parse(_) => (),
+        _ => panic!("expected AppError::Parse"),
+    }
+}
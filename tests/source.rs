@@ -0,0 +1,40 @@
+/*!
+Checks that `Error::source` mirrors whatever `cause` a variant produces,
+for both the automatic `cause;` and an explicit `cause (arg) expr;`.
+*/
+#[macro_use] extern crate error_type;
+
+error_type! {
+    #[derive(Debug)]
+    pub enum AppError {
+        Io(std::io::Error) { cause; },
+        Other(Box<std::error::Error>) {
+            desc (e) e.description();
+            cause (e) Some(&**e);
+        },
+        NotFound {
+            disp "not found";
+            desc "not found";
+        },
+    }
+}
+
+#[test]
+fn test() {
+    use std::error::Error;
+
+    let io_err = std::io::Error::new(std::io::ErrorKind::Other, "oh no!");
+    let e = AppError::Io(io_err);
+    assert!(e.source().is_none());
+    assert_eq!(e.cause().is_some(), e.source().is_some());
+
+    let inner: Box<std::error::Error> = Box::new(
+        std::io::Error::new(std::io::ErrorKind::Other, "inner")
+    );
+    let e = AppError::Other(inner);
+    assert!(e.source().is_some());
+    assert_eq!(e.cause().is_some(), e.source().is_some());
+
+    let e = AppError::NotFound;
+    assert!(e.source().is_none());
+}
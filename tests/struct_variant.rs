@@ -0,0 +1,25 @@
+/*!
+Checks that struct variants (several named fields) work as advertised.
+*/
+#[macro_use] extern crate error_type;
+
+error_type! {
+    #[derive(Debug)]
+    pub enum AppError {
+        Parse { line: usize, msg: String } {
+            disp (fmt) write!(fmt, "{} at line {}", msg, line);
+            desc (_e) "parse error";
+        },
+        Io(std::io::Error) { cause; },
+    }
+}
+
+#[test]
+fn test() {
+    use std::error::Error;
+
+    let e = AppError::Parse { line: 4, msg: "unexpected token".into() };
+    assert_eq!(format!("{}", e), "unexpected token at line 4");
+    assert_eq!(e.description(), "parse error");
+    assert!(e.cause().is_none());
+}
@@ -0,0 +1,42 @@
+/*!
+Checks the `disp "fmt", args...;` and `desc "literal";` sugar forms, across
+tuple, unitary and struct variants.
+*/
+#[macro_use] extern crate error_type;
+
+error_type! {
+    #[derive(Debug)]
+    pub enum AppError {
+        Tuple(i32) {
+            // `(v)` binds the payload -- see the "Format-string sugar"
+            // section of the crate docs.
+            disp (v) "tuple: {}", v;
+            desc "a tuple error";
+        },
+        NotFound {
+            disp "not found";
+            desc "not found";
+        },
+        Parse { line: usize, msg: String } {
+            disp "{} at line {}", msg, line;
+            desc "parse error";
+        },
+    }
+}
+
+#[test]
+fn test() {
+    use std::error::Error;
+
+    let e = AppError::Tuple(42);
+    assert_eq!(format!("{}", e), "tuple: 42");
+    assert_eq!(e.description(), "a tuple error");
+
+    let e = AppError::NotFound;
+    assert_eq!(format!("{}", e), "not found");
+    assert_eq!(e.description(), "not found");
+
+    let e = AppError::Parse { line: 4, msg: "oops".into() };
+    assert_eq!(format!("{}", e), "oops at line 4");
+    assert_eq!(e.description(), "parse error");
+}
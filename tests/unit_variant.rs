@@ -0,0 +1,31 @@
+/*!
+Checks that unitary (payload-less) variants work as advertised.
+*/
+#[macro_use] extern crate error_type;
+
+error_type! {
+    #[derive(Debug)]
+    pub enum AppError {
+        NotFound {
+            disp (_e, fmt) write!(fmt, "not found");
+            desc (_e) "not found";
+        },
+        Io(std::io::Error) { cause; },
+    }
+}
+
+#[test]
+fn test() {
+    use std::error::Error;
+
+    let e = AppError::NotFound;
+    assert_eq!(format!("{}", e), "not found");
+    assert_eq!(e.description(), "not found");
+    assert!(e.cause().is_none());
+
+    let e: AppError = std::io::Error::new(std::io::ErrorKind::Other, "oh no!").into();
+    match e {
+        AppError::Io(_) => (),
+        _ => panic!("expected Io variant"),
+    }
+}